@@ -0,0 +1,102 @@
+// Copyright 2022 The Blaze Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use arrow::{
+    array::{Array, ArrayRef, RecordBatch, RecordBatchOptions},
+    datatypes::SchemaRef,
+};
+use datafusion::common::Result;
+
+use crate::df_execution_err;
+
+/// assemble a batch from separately computed columns, validating each
+/// column's data type and length against the schema and reporting the
+/// offending column on mismatch
+pub fn assemble_batch(schema: SchemaRef, columns: Vec<ArrayRef>) -> Result<RecordBatch> {
+    if columns.len() != schema.fields().len() {
+        return df_execution_err!(
+            "error assembling batch: expect {} columns, got {}",
+            schema.fields().len(),
+            columns.len(),
+        );
+    }
+
+    let num_rows = columns.first().map(|col| col.len()).unwrap_or_default();
+    for (col_idx, (field, col)) in schema.fields().iter().zip(&columns).enumerate() {
+        if col.data_type() != field.data_type() {
+            return df_execution_err!(
+                "error assembling batch: column {col_idx} ({}) expects data type {}, got {}",
+                field.name(),
+                field.data_type(),
+                col.data_type(),
+            );
+        }
+        if col.len() != num_rows {
+            return df_execution_err!(
+                "error assembling batch: column {col_idx} ({}) has {} rows, expect {num_rows}",
+                field.name(),
+                col.len(),
+            );
+        }
+    }
+
+    Ok(RecordBatch::try_new_with_options(
+        schema,
+        columns,
+        &RecordBatchOptions::new().with_row_count(Some(num_rows)),
+    )?)
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use arrow::{
+        array::{ArrayRef, Int32Array, StringArray},
+        datatypes::{DataType, Field, Schema},
+    };
+    use datafusion::common::Result;
+
+    use crate::arrow::assemble::assemble_batch;
+
+    #[test]
+    fn test_assemble_batch() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, true),
+        ]));
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3]));
+        let b: ArrayRef = Arc::new(StringArray::from(vec!["x", "y", "z"]));
+        let short_b: ArrayRef = Arc::new(StringArray::from(vec!["x", "y"]));
+
+        let batch = assemble_batch(schema.clone(), vec![a.clone(), b.clone()])?;
+        assert_eq!(batch.num_rows(), 3);
+
+        let err = assemble_batch(schema.clone(), vec![a.clone(), short_b])
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("column 1 (b) has 2 rows, expect 3"), "{err}");
+
+        let err = assemble_batch(schema.clone(), vec![b.clone(), a.clone()])
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.contains("column 0 (a) expects data type Int32, got Utf8"),
+            "{err}"
+        );
+
+        assert!(assemble_batch(schema, vec![a]).is_err());
+        Ok(())
+    }
+}
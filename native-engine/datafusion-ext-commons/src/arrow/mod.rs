@@ -13,6 +13,7 @@
 // limitations under the License.
 
 pub mod array_size;
+pub mod assemble;
 pub mod cast;
 pub mod coalesce;
 pub mod eq_comparator;
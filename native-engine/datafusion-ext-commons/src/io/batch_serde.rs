@@ -27,7 +27,7 @@ use datafusion::common::Result;
 use unchecked_index::unchecked_index;
 
 use crate::{
-    df_unimplemented_err,
+    df_execution_err, df_unimplemented_err,
     io::{read_bytes_slice, read_len, write_len},
 };
 
@@ -55,42 +55,133 @@ pub fn read_batch(mut input: impl Read, schema: &SchemaRef) -> Result<(usize, Ve
     Ok((num_rows, cols))
 }
 
-pub fn write_array<W: Write>(array: &dyn Array, output: &mut W) -> Result<()> {
-    macro_rules! write_primitive {
-        ($ty:ident) => {{
-            write_primitive_array(
-                as_primitive_array::<paste::paste! {[<$ty Type>]}>(array),
-                output,
-            )?
-        }};
+/// dispatches a data type to the kind of encoding used for it, by invoking
+/// `$handle!(kind)` or `$handle!(kind, ArrowType)`. all data types supported
+/// by batch serde are listed here, and `write_array`, `read_array` and
+/// `is_supported_data_type` all derive their dispatching from this table.
+macro_rules! dispatch_data_type {
+    ($data_type:expr, $handle:ident, $unsupported:expr) => {
+        match $data_type {
+            DataType::Null => $handle!(null),
+            DataType::Boolean => $handle!(boolean),
+            DataType::Int8 => $handle!(primitive, Int8Type),
+            DataType::Int16 => $handle!(primitive, Int16Type),
+            DataType::Int32 => $handle!(primitive, Int32Type),
+            DataType::Int64 => $handle!(primitive, Int64Type),
+            DataType::UInt8 => $handle!(primitive, UInt8Type),
+            DataType::UInt16 => $handle!(primitive, UInt16Type),
+            DataType::UInt32 => $handle!(primitive, UInt32Type),
+            DataType::UInt64 => $handle!(primitive, UInt64Type),
+            DataType::Float16 => $handle!(primitive, Float16Type),
+            DataType::Float32 => $handle!(primitive, Float32Type),
+            DataType::Float64 => $handle!(primitive, Float64Type),
+            DataType::Decimal128(..) => $handle!(primitive, Decimal128Type),
+            DataType::Date32 => $handle!(primitive, Date32Type),
+            DataType::Date64 => $handle!(primitive, Date64Type),
+            DataType::Timestamp(TimeUnit::Second, _) => $handle!(primitive, TimestampSecondType),
+            DataType::Timestamp(TimeUnit::Millisecond, _) => {
+                $handle!(primitive, TimestampMillisecondType)
+            }
+            DataType::Timestamp(TimeUnit::Microsecond, _) => {
+                $handle!(primitive, TimestampMicrosecondType)
+            }
+            DataType::Timestamp(TimeUnit::Nanosecond, _) => {
+                $handle!(primitive, TimestampNanosecondType)
+            }
+            DataType::Duration(TimeUnit::Second) => $handle!(primitive, DurationSecondType),
+            DataType::Duration(TimeUnit::Millisecond) => {
+                $handle!(primitive, DurationMillisecondType)
+            }
+            DataType::Duration(TimeUnit::Microsecond) => {
+                $handle!(primitive, DurationMicrosecondType)
+            }
+            DataType::Duration(TimeUnit::Nanosecond) => {
+                $handle!(primitive, DurationNanosecondType)
+            }
+            DataType::Interval(IntervalUnit::YearMonth) => {
+                $handle!(primitive, IntervalYearMonthType)
+            }
+            DataType::Interval(IntervalUnit::DayTime) => $handle!(primitive, IntervalDayTimeType),
+            DataType::Interval(IntervalUnit::MonthDayNano) => {
+                $handle!(primitive, IntervalMonthDayNanoType)
+            }
+            DataType::Utf8 => $handle!(bytes, Utf8Type),
+            DataType::Binary => $handle!(bytes, BinaryType),
+            DataType::FixedSizeBinary(_) => $handle!(fixed_size_binary),
+            DataType::List(_) => $handle!(list),
+            DataType::FixedSizeList(..) => $handle!(fixed_size_list),
+            DataType::Map(..) => $handle!(map),
+            DataType::Struct(_) => $handle!(struct),
+            _ => $unsupported,
+        }
+    };
+}
+
+/// returns whether a data type can be serialized by `write_array` and
+/// deserialized by `read_array`.
+pub fn is_supported_data_type(data_type: &DataType) -> bool {
+    let children_supported = || match data_type {
+        DataType::List(field) | DataType::FixedSizeList(field, _) | DataType::Map(field, _) => {
+            is_supported_data_type(field.data_type())
+        }
+        DataType::Struct(fields) => fields
+            .iter()
+            .all(|field| is_supported_data_type(field.data_type())),
+        _ => true,
+    };
+    macro_rules! is_supported_kind {
+        (list) => {
+            children_supported()
+        };
+        (fixed_size_list) => {
+            children_supported()
+        };
+        (map) => {
+            children_supported()
+        };
+        (struct) => {
+            children_supported()
+        };
+        ($($other:tt)*) => {
+            true
+        };
     }
-    match array.data_type() {
-        DataType::Null => {}
-        DataType::Boolean => write_boolean_array(as_boolean_array(array), output)?,
-        DataType::Int8 => write_primitive!(Int8),
-        DataType::Int16 => write_primitive!(Int16),
-        DataType::Int32 => write_primitive!(Int32),
-        DataType::Int64 => write_primitive!(Int64),
-        DataType::UInt8 => write_primitive!(UInt8),
-        DataType::UInt16 => write_primitive!(UInt16),
-        DataType::UInt32 => write_primitive!(UInt32),
-        DataType::UInt64 => write_primitive!(UInt64),
-        DataType::Float32 => write_primitive!(Float32),
-        DataType::Float64 => write_primitive!(Float64),
-        DataType::Decimal128(..) => write_primitive!(Decimal128),
-        DataType::Utf8 => write_bytes_array(as_string_array(array), output)?,
-        DataType::Binary => write_bytes_array(as_generic_binary_array::<i32>(array), output)?,
-        DataType::Date32 => write_primitive!(Date32),
-        DataType::Date64 => write_primitive!(Date64),
-        DataType::Timestamp(TimeUnit::Second, _) => write_primitive!(TimestampSecond),
-        DataType::Timestamp(TimeUnit::Millisecond, _) => write_primitive!(TimestampMillisecond),
-        DataType::Timestamp(TimeUnit::Microsecond, _) => write_primitive!(TimestampMicrosecond),
-        DataType::Timestamp(TimeUnit::Nanosecond, _) => write_primitive!(TimestampNanosecond),
-        DataType::List(_field) => write_list_array(as_list_array(array), output)?,
-        DataType::Map(..) => write_map_array(as_map_array(array), output)?,
-        DataType::Struct(_) => write_struct_array(as_struct_array(array), output)?,
-        other => df_unimplemented_err!("unsupported data type: {other}")?,
+    dispatch_data_type!(data_type, is_supported_kind, false)
+}
+
+pub fn write_array<W: Write>(array: &dyn Array, output: &mut W) -> Result<()> {
+    macro_rules! write_kind {
+        (null) => {{}};
+        (boolean) => {
+            write_boolean_array(as_boolean_array(array), output)?
+        };
+        (primitive, $ty:ty) => {
+            write_primitive_array(as_primitive_array::<$ty>(array), output)?
+        };
+        (bytes, $ty:ty) => {
+            write_bytes_array(array.as_bytes::<$ty>(), output)?
+        };
+        (fixed_size_binary) => {
+            write_fixed_size_binary_array(array.as_fixed_size_binary(), output)?
+        };
+        (list) => {
+            write_list_array(as_list_array(array), output)?
+        };
+        (fixed_size_list) => {
+            write_fixed_size_list_array(array.as_fixed_size_list(), output)?
+        };
+        (map) => {
+            write_map_array(as_map_array(array), output)?
+        };
+        (struct) => {
+            write_struct_array(as_struct_array(array), output)?
+        };
     }
+    dispatch_data_type!(
+        array.data_type(),
+        write_kind,
+        df_unimplemented_err!("unsupported data type: {}", array.data_type())?
+    );
     Ok(())
 }
 
@@ -99,44 +190,55 @@ pub fn read_array<R: Read>(
     data_type: &DataType,
     num_rows: usize,
 ) -> Result<ArrayRef> {
-    macro_rules! read_primitive {
-        ($ty:ident) => {{
-            read_primitive_array::<_, paste::paste! {[<$ty Type>]}>(num_rows, input)?
+    macro_rules! read_kind {
+        (null) => {
+            Arc::new(NullArray::new(num_rows))
+        };
+        (boolean) => {
+            read_boolean_array(num_rows, input)?
+        };
+        (primitive, $ty:ty) => {
+            read_primitive_array::<_, $ty>(num_rows, input, data_type)?
+        };
+        (bytes, $ty:ty) => {
+            read_bytes_array(num_rows, input, data_type.clone())?
+        };
+        (fixed_size_binary) => {{
+            let DataType::FixedSizeBinary(width) = data_type else {
+                unreachable!()
+            };
+            read_fixed_size_binary_array(num_rows, input, *width)?
         }};
-    }
-    Ok(match data_type {
-        DataType::Null => Arc::new(NullArray::new(num_rows)),
-        DataType::Boolean => read_boolean_array(num_rows, input)?,
-        DataType::Int8 => read_primitive!(Int8),
-        DataType::Int16 => read_primitive!(Int16),
-        DataType::Int32 => read_primitive!(Int32),
-        DataType::Int64 => read_primitive!(Int64),
-        DataType::UInt8 => read_primitive!(UInt8),
-        DataType::UInt16 => read_primitive!(UInt16),
-        DataType::UInt32 => read_primitive!(UInt32),
-        DataType::UInt64 => read_primitive!(UInt64),
-        DataType::Float32 => read_primitive!(Float32),
-        DataType::Float64 => read_primitive!(Float64),
-        DataType::Decimal128(prec, scale) => Arc::new(
-            as_primitive_array::<Decimal128Type>(&read_primitive!(Decimal128))
-                .clone()
-                .with_precision_and_scale(*prec, *scale)?,
-        ),
-        DataType::Date32 => read_primitive!(Date32),
-        DataType::Date64 => read_primitive!(Date64),
-        DataType::Timestamp(TimeUnit::Second, _) => read_primitive!(TimestampSecond),
-        DataType::Timestamp(TimeUnit::Millisecond, _) => read_primitive!(TimestampMillisecond),
-        DataType::Timestamp(TimeUnit::Microsecond, _) => read_primitive!(TimestampMicrosecond),
-        DataType::Timestamp(TimeUnit::Nanosecond, _) => read_primitive!(TimestampNanosecond),
-        DataType::Utf8 => read_bytes_array(num_rows, input, DataType::Utf8)?,
-        DataType::Binary => read_bytes_array(num_rows, input, DataType::Binary)?,
-        DataType::List(list_field) => read_list_array(num_rows, input, list_field)?,
-        DataType::Map(map_field, is_sorted) => {
+        (list) => {{
+            let DataType::List(list_field) = data_type else {
+                unreachable!()
+            };
+            read_list_array(num_rows, input, list_field)?
+        }};
+        (fixed_size_list) => {{
+            let DataType::FixedSizeList(list_field, list_size) = data_type else {
+                unreachable!()
+            };
+            read_fixed_size_list_array(num_rows, input, list_field, *list_size)?
+        }};
+        (map) => {{
+            let DataType::Map(map_field, is_sorted) = data_type else {
+                unreachable!()
+            };
             read_map_array(num_rows, input, map_field, *is_sorted)?
-        }
-        DataType::Struct(fields) => read_struct_array(num_rows, input, fields)?,
-        other => df_unimplemented_err!("unsupported data type: {other}")?,
-    })
+        }};
+        (struct) => {{
+            let DataType::Struct(fields) = data_type else {
+                unreachable!()
+            };
+            read_struct_array(num_rows, input, fields)?
+        }};
+    }
+    Ok(dispatch_data_type!(
+        data_type,
+        read_kind,
+        df_unimplemented_err!("unsupported data type: {data_type}")?
+    ))
 }
 
 fn write_bits_buffer<W: Write>(
@@ -172,7 +274,7 @@ fn write_primitive_array<W: Write, PT: ArrowPrimitiveType>(
     let offset = array.offset();
     let len = array.len();
     let array_data = array.to_data();
-    if let Some(null_buffer) = array_data.nulls() {
+    if let Some(null_buffer) = array_data.nulls().filter(|nb| nb.null_count() > 0) {
         write_len(1, output)?;
         write_bits_buffer(
             null_buffer.buffer(),
@@ -187,9 +289,12 @@ fn write_primitive_array<W: Write, PT: ArrowPrimitiveType>(
     Ok(())
 }
 
+/// reads a primitive array, tagged with `data_type` so that parameters like
+/// decimal precision/scale and timestamp timezone are kept
 fn read_primitive_array<R: Read, PT: ArrowPrimitiveType>(
     num_rows: usize,
     input: &mut R,
+    data_type: &DataType,
 ) -> Result<ArrayRef> {
     let has_null_buffer = read_len(input)? == 1;
     let null_buffer: Option<Buffer> = if has_null_buffer {
@@ -205,7 +310,7 @@ fn read_primitive_array<R: Read, PT: ArrowPrimitiveType>(
     };
 
     let array_data = ArrayData::try_new(
-        PT::DATA_TYPE,
+        data_type.clone(),
         num_rows,
         null_buffer,
         0,
@@ -216,7 +321,7 @@ fn read_primitive_array<R: Read, PT: ArrowPrimitiveType>(
 }
 
 fn write_list_array<W: Write>(array: &ListArray, output: &mut W) -> Result<()> {
-    if let Some(null_buffer) = array.to_data().nulls() {
+    if let Some(null_buffer) = array.to_data().nulls().filter(|nb| nb.null_count() > 0) {
         write_len(1, output)?;
         write_bits_buffer(
             null_buffer.buffer(),
@@ -277,9 +382,58 @@ fn read_list_array<R: Read>(
     Ok(make_array(array_data))
 }
 
+fn write_fixed_size_list_array<W: Write>(array: &FixedSizeListArray, output: &mut W) -> Result<()> {
+    if let Some(null_buffer) = array.to_data().nulls().filter(|nb| nb.null_count() > 0) {
+        write_len(1, output)?;
+        write_bits_buffer(
+            null_buffer.buffer(),
+            null_buffer.offset(),
+            null_buffer.len(),
+            output,
+        )?;
+    } else {
+        write_len(0, output)?;
+    }
+
+    // every slot (including null ones) holds exactly list_size values
+    let list_size = array.value_length() as usize;
+    let values = array
+        .values()
+        .slice(array.value_offset(0) as usize, array.len() * list_size);
+    write_array(&values, output)?;
+    Ok(())
+}
+
+fn read_fixed_size_list_array<R: Read>(
+    num_rows: usize,
+    input: &mut R,
+    list_field: &FieldRef,
+    list_size: i32,
+) -> Result<ArrayRef> {
+    let has_null_buffer = read_len(input)? == 1;
+    let null_buffer: Option<Buffer> = if has_null_buffer {
+        Some(read_bits_buffer(input, num_rows)?)
+    } else {
+        None
+    };
+
+    let values_len = num_rows * list_size as usize;
+    let values = read_array(input, list_field.data_type(), values_len)?;
+
+    let array_data = ArrayData::try_new(
+        DataType::FixedSizeList(list_field.clone(), list_size),
+        num_rows,
+        null_buffer,
+        0,
+        vec![],
+        vec![values.into_data()],
+    )?;
+    Ok(make_array(array_data))
+}
+
 fn write_map_array<W: Write>(array: &MapArray, output: &mut W) -> Result<()> {
     let array_data = array.to_data();
-    if let Some(null_buffer) = array_data.nulls() {
+    if let Some(null_buffer) = array_data.nulls().filter(|nb| nb.null_count() > 0) {
         write_len(1, output)?;
         write_bits_buffer(
             null_buffer.buffer(),
@@ -368,7 +522,7 @@ fn read_map_array<R: Read>(
 
 fn write_struct_array<W: Write>(array: &StructArray, output: &mut W) -> Result<()> {
     let array_data = array.to_data();
-    if let Some(null_buffer) = array_data.nulls() {
+    if let Some(null_buffer) = array_data.nulls().filter(|nb| nb.null_count() > 0) {
         write_len(1, output)?;
         write_bits_buffer(
             null_buffer.buffer(),
@@ -411,7 +565,7 @@ fn read_struct_array<R: Read>(num_rows: usize, input: &mut R, fields: &Fields) -
 
 fn write_boolean_array<W: Write>(array: &BooleanArray, output: &mut W) -> Result<()> {
     let array_data = array.to_data();
-    if let Some(null_buffer) = array_data.nulls() {
+    if let Some(null_buffer) = array_data.nulls().filter(|nb| nb.null_count() > 0) {
         write_len(1, output)?;
         write_bits_buffer(
             null_buffer.buffer(),
@@ -459,7 +613,7 @@ fn write_bytes_array<T: ByteArrayType<Offset = i32>, W: Write>(
     array: &GenericByteArray<T>,
     output: &mut W,
 ) -> Result<()> {
-    if let Some(null_buffer) = array.to_data().nulls() {
+    if let Some(null_buffer) = array.to_data().nulls().filter(|nb| nb.null_count() > 0) {
         write_len(1, output)?;
         write_bits_buffer(
             null_buffer.buffer(),
@@ -521,6 +675,60 @@ fn read_bytes_array<R: Read>(
     Ok(make_array(array_data))
 }
 
+fn write_fixed_size_binary_array<W: Write>(
+    array: &FixedSizeBinaryArray,
+    output: &mut W,
+) -> Result<()> {
+    let array_data = array.to_data();
+    if let Some(null_buffer) = array_data.nulls().filter(|nb| nb.null_count() > 0) {
+        write_len(1, output)?;
+        write_bits_buffer(
+            null_buffer.buffer(),
+            null_buffer.offset(),
+            null_buffer.len(),
+            output,
+        )?;
+    } else {
+        write_len(0, output)?;
+    }
+
+    // write byte width so that readers can detect mismatched schemas
+    let width = array.value_length() as usize;
+    write_len(width, output)?;
+    output.write_all(
+        &array_data.buffers()[0][array_data.offset() * width..][..array.len() * width],
+    )?;
+    Ok(())
+}
+
+fn read_fixed_size_binary_array<R: Read>(
+    num_rows: usize,
+    input: &mut R,
+    width: i32,
+) -> Result<ArrayRef> {
+    let has_null_buffer = read_len(input)? == 1;
+    let null_buffer: Option<Buffer> = if has_null_buffer {
+        Some(read_bits_buffer(input, num_rows)?)
+    } else {
+        None
+    };
+
+    let data_width = read_len(input)?;
+    if data_width != width as usize {
+        df_execution_err!("fixed size binary width mismatch: expect {width}, got {data_width}")?;
+    }
+    let data_buffer = Buffer::from_vec(read_bytes_slice(input, num_rows * data_width)?.into());
+    let array_data = ArrayData::try_new(
+        DataType::FixedSizeBinary(width),
+        num_rows,
+        null_buffer,
+        0,
+        vec![data_buffer],
+        vec![],
+    )?;
+    Ok(make_array(array_data))
+}
+
 fn write_primitive_raw_array<T: Default + Copy + Sized, W: Write>(
     array: &[T],
     output: &mut W,
@@ -575,16 +783,104 @@ fn transpose_raw_bytes(src: &[u8], dest: &mut [u8], num_items: usize, num_item_b
 mod test {
     use std::{io::Cursor, sync::Arc};
 
-    use arrow::{array::*, datatypes::*, record_batch::RecordBatch};
+    use arrow::{
+        array::*,
+        buffer::{BooleanBuffer, NullBuffer, OffsetBuffer},
+        datatypes::*,
+        record_batch::RecordBatch,
+    };
     use datafusion::assert_batches_eq;
 
     use crate::io::{
         batch_serde::{
-            read_batch, read_primitive_raw_array, write_batch, write_primitive_raw_array,
+            is_supported_data_type, read_array, read_batch, read_primitive_raw_array, write_array,
+            write_batch, write_primitive_raw_array,
         },
         recover_named_batch,
     };
 
+    #[test]
+    fn test_supported_data_types_in_sync() {
+        let list_of = |dt: DataType| DataType::List(Arc::new(Field::new("item", dt, true)));
+        let fixed_size_list_of =
+            |dt: DataType| DataType::FixedSizeList(Arc::new(Field::new("item", dt, true)), 3);
+        let struct_of = |dt: DataType| {
+            DataType::Struct(Fields::from(vec![
+                Field::new("a", DataType::Int32, true),
+                Field::new("b", dt, true),
+            ]))
+        };
+        let map_of = |dt: DataType| {
+            DataType::Map(
+                Arc::new(Field::new(
+                    "entries",
+                    DataType::Struct(Fields::from(vec![
+                        Field::new("key", DataType::Utf8, false),
+                        Field::new("value", dt, true),
+                    ])),
+                    false,
+                )),
+                false,
+            )
+        };
+
+        let data_types = [
+            DataType::Null,
+            DataType::Boolean,
+            DataType::Int8,
+            DataType::Int16,
+            DataType::Int32,
+            DataType::Int64,
+            DataType::UInt8,
+            DataType::UInt16,
+            DataType::UInt32,
+            DataType::UInt64,
+            DataType::Float16,
+            DataType::Float32,
+            DataType::Float64,
+            DataType::Decimal128(38, 10),
+            DataType::Decimal256(76, 10),
+            DataType::Utf8,
+            DataType::LargeUtf8,
+            DataType::Binary,
+            DataType::LargeBinary,
+            DataType::FixedSizeBinary(16),
+            DataType::Date32,
+            DataType::Date64,
+            DataType::Timestamp(TimeUnit::Microsecond, None),
+            DataType::Timestamp(TimeUnit::Millisecond, Some("UTC".into())),
+            DataType::Duration(TimeUnit::Second),
+            DataType::Duration(TimeUnit::Millisecond),
+            DataType::Duration(TimeUnit::Microsecond),
+            DataType::Duration(TimeUnit::Nanosecond),
+            DataType::Interval(IntervalUnit::YearMonth),
+            DataType::Interval(IntervalUnit::DayTime),
+            DataType::Interval(IntervalUnit::MonthDayNano),
+            DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8)),
+            list_of(DataType::Int32),
+            list_of(DataType::Float16),
+            fixed_size_list_of(DataType::Float32),
+            fixed_size_list_of(DataType::LargeUtf8),
+            struct_of(DataType::Utf8),
+            struct_of(DataType::LargeUtf8),
+            map_of(DataType::Int64),
+            map_of(DataType::LargeBinary),
+        ];
+
+        for data_type in data_types {
+            let array = new_null_array(&data_type, 3);
+            let mut buf = vec![];
+            let write_ok = write_array(&array, &mut buf).is_ok();
+            let read_ok = write_ok && read_array(&mut Cursor::new(&buf), &data_type, 3).is_ok();
+            let supported = is_supported_data_type(&data_type);
+            assert_eq!(
+                write_ok, supported,
+                "write_array out of sync for {data_type}"
+            );
+            assert_eq!(read_ok, supported, "read_array out of sync for {data_type}");
+        }
+    }
+
     #[test]
     fn test_primitive_raw_bytes() {
         let src = vec![1, 2, 3, 4, 5, 6];
@@ -594,6 +890,33 @@ mod test {
         assert_eq!(out, src)
     }
 
+    #[test]
+    fn test_drop_null_buffer_without_nulls() {
+        let arrays: Vec<ArrayRef> = vec![
+            Arc::new(Int32Array::new(
+                vec![1, 2, 3].into(),
+                Some(NullBuffer::new_valid(3)),
+            )),
+            Arc::new(StringArray::new(
+                OffsetBuffer::from_lengths([1, 1, 1]),
+                b"abc".into(),
+                Some(NullBuffer::new_valid(3)),
+            )),
+            Arc::new(BooleanArray::new(
+                BooleanBuffer::from_iter([true, false, true]),
+                Some(NullBuffer::new_valid(3)),
+            )),
+        ];
+        for array in arrays {
+            assert!(array.nulls().is_some());
+            let mut buf = vec![];
+            write_array(&array, &mut buf).unwrap();
+            let decoded = read_array(&mut Cursor::new(&buf), array.data_type(), 3).unwrap();
+            assert!(decoded.nulls().is_none());
+            assert_eq!(&decoded, &array);
+        }
+    }
+
     #[test]
     fn test_write_and_read_batch() {
         let array1: ArrayRef = Arc::new(StringArray::from_iter([
@@ -643,6 +966,113 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_write_and_read_float16() {
+        let array = arrow::compute::cast(
+            &Float32Array::from(vec![Some(1.5), None, Some(-0.25), None, Some(65504.0)]),
+            &DataType::Float16,
+        )
+        .unwrap();
+        assert_eq!(array.data_type(), &DataType::Float16);
+
+        let mut buf = vec![];
+        write_array(&array, &mut buf).unwrap();
+        let decoded = read_array(&mut Cursor::new(&buf), &DataType::Float16, 5).unwrap();
+        assert_eq!(&decoded, &array);
+
+        let sliced = array.slice(1, 3);
+        let mut buf = vec![];
+        write_array(&sliced, &mut buf).unwrap();
+        let decoded = read_array(&mut Cursor::new(&buf), &DataType::Float16, 3).unwrap();
+        assert_eq!(&decoded, &sliced);
+    }
+
+    #[test]
+    fn test_write_and_read_timestamp_with_timezone() {
+        let array: ArrayRef = Arc::new(
+            TimestampMicrosecondArray::from(vec![Some(1_700_000_000_000_000), None, Some(0)])
+                .with_timezone("UTC"),
+        );
+        let batch = RecordBatch::try_from_iter_with_nullable(vec![("ts", array, true)]).unwrap();
+        assert_eq!(
+            batch.column(0).data_type(),
+            &DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into())),
+        );
+
+        // test read after write
+        let mut buf = vec![];
+        write_batch(batch.num_rows(), batch.columns(), &mut buf).unwrap();
+        let mut cursor = Cursor::new(buf);
+        let (decoded_num_rows, decoded_cols) = read_batch(&mut cursor, &batch.schema()).unwrap();
+        assert_eq!(decoded_cols[0].data_type(), batch.column(0).data_type());
+        assert_eq!(
+            recover_named_batch(decoded_num_rows, &decoded_cols, batch.schema()).unwrap(),
+            batch
+        );
+
+        // test read after write sliced
+        let sliced = batch.slice(1, 2);
+        let mut buf = vec![];
+        write_batch(sliced.num_rows(), sliced.columns(), &mut buf).unwrap();
+        let mut cursor = Cursor::new(buf);
+        let (decoded_num_rows, decoded_cols) = read_batch(&mut cursor, &batch.schema()).unwrap();
+        assert_eq!(
+            recover_named_batch(decoded_num_rows, &decoded_cols, sliced.schema()).unwrap(),
+            sliced
+        );
+    }
+
+    #[test]
+    fn test_write_and_read_duration() {
+        let array: ArrayRef = Arc::new(DurationMicrosecondArray::from(vec![
+            Some(1_000_000),
+            None,
+            Some(-42),
+            None,
+            Some(i64::MAX),
+        ]));
+
+        let mut buf = vec![];
+        write_array(&array, &mut buf).unwrap();
+        let decoded = read_array(&mut Cursor::new(&buf), array.data_type(), 5).unwrap();
+        assert_eq!(&decoded, &array);
+
+        let sliced = array.slice(1, 3);
+        let mut buf = vec![];
+        write_array(&sliced, &mut buf).unwrap();
+        let decoded = read_array(&mut Cursor::new(&buf), sliced.data_type(), 3).unwrap();
+        assert_eq!(&decoded, &sliced);
+    }
+
+    #[test]
+    fn test_write_and_read_interval() {
+        let arrays: Vec<ArrayRef> = vec![
+            Arc::new(IntervalYearMonthArray::from(vec![Some(14), None, Some(-3)])),
+            Arc::new(IntervalDayTimeArray::from(vec![
+                Some(IntervalDayTime::new(1, 3_600_000)),
+                None,
+                Some(IntervalDayTime::new(-2, -1)),
+            ])),
+            Arc::new(IntervalMonthDayNanoArray::from(vec![
+                Some(IntervalMonthDayNano::new(1, 2, 3)),
+                None,
+                Some(IntervalMonthDayNano::new(-12, 31, 86_399_999_999_999)),
+            ])),
+        ];
+        for array in arrays {
+            let mut buf = vec![];
+            write_array(&array, &mut buf).unwrap();
+            let decoded = read_array(&mut Cursor::new(&buf), array.data_type(), 3).unwrap();
+            assert_eq!(&decoded, &array);
+
+            let sliced = array.slice(1, 2);
+            let mut buf = vec![];
+            write_array(&sliced, &mut buf).unwrap();
+            let decoded = read_array(&mut Cursor::new(&buf), sliced.data_type(), 2).unwrap();
+            assert_eq!(&decoded, &sliced);
+        }
+    }
+
     #[test]
     fn test_write_and_read_batch_for_list() {
         let data = vec![
@@ -711,6 +1141,44 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_write_and_read_batch_for_fixed_size_list() {
+        let data = vec![
+            Some(vec![Some(0.1), Some(0.2), Some(0.3)]),
+            None,
+            Some(vec![Some(1.5), None, Some(-2.5)]),
+            Some(vec![Some(0.0), Some(0.0), Some(1.0)]),
+        ];
+        let embedding_array: ArrayRef =
+            Arc::new(FixedSizeListArray::from_iter_primitive::<Float32Type, _, _>(data, 3));
+        let batch = RecordBatch::try_from_iter_with_nullable(vec![
+            ("embedding1", embedding_array.clone(), true),
+            ("embedding2", embedding_array.clone(), true),
+        ])
+        .unwrap();
+
+        // test read after write
+        let mut buf = vec![];
+        write_batch(batch.num_rows(), batch.columns(), &mut buf).unwrap();
+        let mut cursor = Cursor::new(buf);
+        let (decoded_num_rows, decoded_cols) = read_batch(&mut cursor, &batch.schema()).unwrap();
+        assert_eq!(
+            recover_named_batch(decoded_num_rows, &decoded_cols, batch.schema()).unwrap(),
+            batch
+        );
+
+        // test read after write sliced
+        let sliced = batch.slice(1, 2);
+        let mut buf = vec![];
+        write_batch(sliced.num_rows(), sliced.columns(), &mut buf).unwrap();
+        let mut cursor = Cursor::new(buf);
+        let (decoded_num_rows, decoded_cols) = read_batch(&mut cursor, &batch.schema()).unwrap();
+        assert_eq!(
+            recover_named_batch(decoded_num_rows, &decoded_cols, sliced.schema()).unwrap(),
+            sliced
+        );
+    }
+
     #[test]
     fn test_write_and_read_batch_for_map() {
         let map_array: ArrayRef = Arc::new(
@@ -796,4 +1264,39 @@ mod test {
             sliced
         );
     }
+
+    #[test]
+    fn test_write_and_read_fixed_size_binary() {
+        let array: ArrayRef = Arc::new(
+            FixedSizeBinaryArray::try_from_sparse_iter_with_size(
+                [
+                    Some(b"0123456789abcdef".to_vec()),
+                    None,
+                    Some(b"fedcba9876543210".to_vec()),
+                    Some(b"aaaabbbbccccdddd".to_vec()),
+                ]
+                .into_iter(),
+                16,
+            )
+            .unwrap(),
+        );
+
+        // test read after write
+        let mut buf = vec![];
+        write_array(&array, &mut buf).unwrap();
+        let decoded = read_array(&mut Cursor::new(&buf), array.data_type(), 4).unwrap();
+        assert_eq!(&decoded, &array);
+
+        // test read after write sliced
+        let sliced = array.slice(1, 2);
+        let mut buf = vec![];
+        write_array(&sliced, &mut buf).unwrap();
+        let decoded = read_array(&mut Cursor::new(&buf), sliced.data_type(), 2).unwrap();
+        assert_eq!(&decoded, &sliced);
+
+        // test reading with mismatched width
+        let mut buf = vec![];
+        write_array(&array, &mut buf).unwrap();
+        assert!(read_array(&mut Cursor::new(&buf), &DataType::FixedSizeBinary(8), 4).is_err());
+    }
 }
@@ -16,10 +16,10 @@ use std::io::{Read, Write};
 
 use arrow::{
     array::{Array, ArrayRef, RecordBatchOptions},
-    datatypes::SchemaRef,
+    datatypes::{DataType, SchemaRef},
     record_batch::RecordBatch,
 };
-pub use batch_serde::{read_array, write_array};
+pub use batch_serde::{is_supported_data_type, read_array, write_array};
 use datafusion::common::Result;
 pub use scalar_serde::{read_scalar, write_scalar};
 
@@ -85,6 +85,64 @@ pub fn read_one_batch(
     return Ok(Some((num_rows, cols)));
 }
 
+/// a reader over a sequence of byte chunks (e.g. from a network stream), chunk
+/// boundaries may fall anywhere inside a serialized batch
+pub struct ChunkStreamReader<I: Iterator<Item = B>, B: AsRef<[u8]>> {
+    chunks: I,
+    cur_chunk: Option<B>,
+    cur_pos: usize,
+}
+
+impl<I: Iterator<Item = B>, B: AsRef<[u8]>> ChunkStreamReader<I, B> {
+    pub fn new(chunks: impl IntoIterator<IntoIter = I>) -> Self {
+        Self {
+            chunks: chunks.into_iter(),
+            cur_chunk: None,
+            cur_pos: 0,
+        }
+    }
+}
+
+impl<I: Iterator<Item = B>, B: AsRef<[u8]>> Read for ChunkStreamReader<I, B> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        loop {
+            if let Some(chunk) = &self.cur_chunk {
+                let remaining = &chunk.as_ref()[self.cur_pos..];
+                if !remaining.is_empty() {
+                    let len = remaining.len().min(buf.len());
+                    buf[..len].copy_from_slice(&remaining[..len]);
+                    self.cur_pos += len;
+                    return Ok(len);
+                }
+            }
+
+            // current chunk is exhausted, move to next non-empty chunk
+            match self.chunks.next() {
+                Some(chunk) => {
+                    self.cur_chunk = Some(chunk);
+                    self.cur_pos = 0;
+                }
+                None => {
+                    self.cur_chunk = None;
+                    return Ok(0);
+                }
+            }
+        }
+    }
+}
+
+/// returns the index and data type of the first column in the batch that
+/// cannot be serialized by `write_one_batch`, including nested fields
+pub fn first_unsupported_field(batch: &RecordBatch) -> Option<(usize, DataType)> {
+    batch
+        .schema()
+        .fields()
+        .iter()
+        .enumerate()
+        .find(|(_, field)| !is_supported_data_type(field.data_type()))
+        .map(|(idx, field)| (idx, field.data_type().clone()))
+}
+
 pub fn recover_named_batch(
     num_rows: usize,
     cols: &[ArrayRef],
@@ -160,3 +218,91 @@ pub fn read_bytes_slice<R: Read>(input: &mut R, len: usize) -> std::io::Result<B
     input.read_exact(byte_slice.as_mut())?;
     Ok(byte_slice)
 }
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use arrow::{
+        array::{ArrayRef, DictionaryArray, Int32Array, ListArray, StringArray},
+        datatypes::{DataType, Int32Type},
+        record_batch::RecordBatch,
+    };
+
+    use crate::io::{first_unsupported_field, read_one_batch, write_one_batch, ChunkStreamReader};
+
+    #[test]
+    fn test_chunk_stream_reader() {
+        let ints: ArrayRef = Arc::new(Int32Array::from(vec![Some(1), None, Some(3)]));
+        let strs: ArrayRef = Arc::new(StringArray::from(vec![Some("hello"), Some("world"), None]));
+        let batch = RecordBatch::try_from_iter(vec![("ints", ints), ("strs", strs)]).unwrap();
+
+        let mut buf = vec![];
+        write_one_batch(batch.num_rows(), batch.columns(), &mut buf).unwrap();
+        write_one_batch(batch.num_rows(), batch.columns(), &mut buf).unwrap();
+
+        // split into chunks of arbitrary sizes, including empty ones
+        let mut chunks = vec![];
+        let mut remaining = &buf[..];
+        for chunk_size in [1, 0, 3, 7, 2, 0, 11].into_iter().cycle() {
+            if remaining.is_empty() {
+                break;
+            }
+            let (chunk, rest) = remaining.split_at(chunk_size.min(remaining.len()));
+            chunks.push(bytes::Bytes::copy_from_slice(chunk));
+            remaining = rest;
+        }
+        assert!(chunks.len() > 2);
+
+        let mut reader = ChunkStreamReader::new(chunks);
+        for _ in 0..2 {
+            let (num_rows, cols) = read_one_batch(&mut reader, &batch.schema())
+                .unwrap()
+                .unwrap();
+            assert_eq!(num_rows, batch.num_rows());
+            assert_eq!(cols, batch.columns());
+        }
+        assert!(read_one_batch(&mut reader, &batch.schema())
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_first_unsupported_field() {
+        let ints: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3]));
+        let strs: ArrayRef = Arc::new(StringArray::from(vec!["a", "b", "c"]));
+        let list: ArrayRef = Arc::new(ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1)]),
+            None,
+            Some(vec![]),
+        ]));
+        let dict: ArrayRef = Arc::new(
+            vec!["x", "y", "x"]
+                .into_iter()
+                .collect::<DictionaryArray<Int32Type>>(),
+        );
+
+        let batch = RecordBatch::try_from_iter(vec![
+            ("ints", ints.clone()),
+            ("strs", strs.clone()),
+            ("list", list.clone()),
+        ])
+        .unwrap();
+        assert_eq!(first_unsupported_field(&batch), None);
+
+        let batch = RecordBatch::try_from_iter(vec![
+            ("ints", ints),
+            ("strs", strs),
+            ("dict", dict),
+            ("list", list),
+        ])
+        .unwrap();
+        assert_eq!(
+            first_unsupported_field(&batch),
+            Some((
+                2,
+                DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8))
+            ))
+        );
+    }
+}
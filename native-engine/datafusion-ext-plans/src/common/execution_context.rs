@@ -27,7 +27,7 @@ use datafusion::{
     common::Result,
     execution::{RecordBatchStream, SendableRecordBatchStream, TaskContext},
     physical_plan::{
-        metrics::{BaselineMetrics, Count, ExecutionPlanMetricsSet, MetricBuilder, Time},
+        metrics::{BaselineMetrics, Count, ExecutionPlanMetricsSet, Gauge, MetricBuilder, Time},
         stream::{RecordBatchReceiverStream, RecordBatchStreamAdapter},
         ExecutionPlan,
     },
@@ -110,6 +110,10 @@ impl ExecutionContext {
             .counter(name.to_owned(), self.partition_id)
     }
 
+    pub fn register_gauge_metric(&self, name: &str) -> Gauge {
+        MetricBuilder::new(self.execution_plan_metrics()).gauge(name.to_owned(), self.partition_id)
+    }
+
     pub fn coalesce_with_default_batch_size(
         self: &Arc<Self>,
         input: SendableRecordBatchStream,
@@ -17,13 +17,13 @@
 
 use std::io::{BufReader, Read, Take, Write};
 
-use arrow::{array::ArrayRef, datatypes::SchemaRef};
+use arrow::{array::ArrayRef, datatypes::SchemaRef, record_batch::RecordBatch};
 use blaze_jni_bridge::{conf, conf::StringConf, is_jni_bridge_inited};
 use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
 use datafusion::common::Result;
 use datafusion_ext_commons::{
     df_execution_err,
-    io::{read_one_batch, write_one_batch},
+    io::{read_one_batch, recover_named_batch, write_one_batch},
 };
 use once_cell::sync::OnceCell;
 
@@ -166,6 +166,75 @@ impl<R: Read> IpcCompressionReader<R> {
         }
         read_one_batch(&mut Reader(self), schema)
     }
+
+    /// read a batch written with `segment_schema` and project it down to the
+    /// fields of `expected_schema`, matched by name. extra columns in the
+    /// segment are ignored, missing ones or ones with a different data type
+    /// result in an error.
+    pub fn read_batch_projected(
+        &mut self,
+        segment_schema: &SchemaRef,
+        expected_schema: &SchemaRef,
+    ) -> Result<Option<(usize, Vec<ArrayRef>)>> {
+        let projection = expected_schema
+            .fields()
+            .iter()
+            .map(|field| match segment_schema.index_of(field.name()) {
+                Ok(idx) if segment_schema.field(idx).data_type() == field.data_type() => Ok(idx),
+                Ok(idx) => df_execution_err!(
+                    "mismatched data type of field in segment schema: {}, expect {}, got {}",
+                    field.name(),
+                    field.data_type(),
+                    segment_schema.field(idx).data_type(),
+                ),
+                Err(_) => df_execution_err!("missing field in segment schema: {}", field.name()),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(self.read_batch(segment_schema)?.map(|(num_rows, cols)| {
+            let projected_cols = projection.iter().map(|&idx| cols[idx].clone()).collect();
+            (num_rows, projected_cols)
+        }))
+    }
+}
+
+/// synchronous iterator over all batches of an `IpcCompressionReader`.
+/// the iterator is fused after the first error, since the underlying reader
+/// cannot be resumed once reading has failed.
+pub struct RecordBatchIter<R: Read + 'static> {
+    reader: IpcCompressionReader<R>,
+    schema: SchemaRef,
+    done: bool,
+}
+
+impl<R: Read> RecordBatchIter<R> {
+    pub fn new(reader: IpcCompressionReader<R>, schema: SchemaRef) -> Self {
+        Self {
+            reader,
+            schema,
+            done: false,
+        }
+    }
+}
+
+impl<R: Read> Iterator for RecordBatchIter<R> {
+    type Item = Result<RecordBatch>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let next = self
+            .reader
+            .read_batch(&self.schema)
+            .transpose()
+            .map(|read_result| {
+                let (num_rows, cols) = read_result?;
+                recover_named_batch(num_rows, &cols, self.schema.clone())
+            });
+        self.done = !matches!(next, Some(Ok(_)));
+        next
+    }
 }
 
 pub enum IoCompressionWriter<W: Write> {
@@ -336,4 +405,110 @@ mod tests {
         assert!(reader.read_batch(&schema)?.is_none());
         Ok(())
     }
+
+    #[test]
+    fn test_record_batch_iter() -> Result<(), Box<dyn Error>> {
+        let mut buf = vec![];
+        let mut writer = IpcCompressionWriter::new(&mut buf);
+
+        let schema = Arc::new(Schema::new(vec![Field::new("", DataType::Utf8, true)]));
+        for i in 0..5 {
+            let array: ArrayRef = Arc::new(StringArray::from(vec![
+                Some(format!("hello{i}")),
+                None,
+                Some(format!("world{i}")),
+            ]));
+            writer.write_batch(3, &[array])?;
+        }
+        writer.finish_current_buf()?;
+
+        let mut drained = vec![];
+        let mut reader = IpcCompressionReader::new(Cursor::new(buf.clone()));
+        while let Some((num_rows, cols)) = reader.read_batch(&schema)? {
+            drained.push(recover_named_batch(num_rows, &cols, schema.clone())?);
+        }
+
+        let reader = IpcCompressionReader::new(Cursor::new(buf));
+        let collected = RecordBatchIter::new(reader, schema).collect::<Result<Vec<_>>>()?;
+        assert_eq!(collected.len(), 5);
+        assert_eq!(collected, drained);
+        Ok(())
+    }
+
+    #[test]
+    fn test_record_batch_iter_fused_after_error() -> Result<(), Box<dyn Error>> {
+        let mut buf = vec![];
+        let mut writer = IpcCompressionWriter::new(&mut buf);
+
+        let schema = Arc::new(Schema::new(vec![Field::new("", DataType::Utf8, true)]));
+        let array: ArrayRef = Arc::new(StringArray::from(vec![Some("hello"), None]));
+        writer.write_batch(2, &[array])?;
+        writer.finish_current_buf()?;
+
+        // corrupt the frame header of the compressed block
+        buf[4] ^= 0xff;
+
+        let reader = IpcCompressionReader::new(Cursor::new(buf));
+        let mut iter = RecordBatchIter::new(reader, schema);
+        assert!(matches!(iter.next(), Some(Err(_))));
+        assert!(iter.next().is_none());
+        assert!(iter.next().is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_ipc_compression_read_projected() -> Result<(), Box<dyn Error>> {
+        let mut buf = vec![];
+        let mut writer = IpcCompressionWriter::new(&mut buf);
+
+        let a: ArrayRef = Arc::new(StringArray::from(vec![Some("a1"), Some("a2")]));
+        let b: ArrayRef = Arc::new(StringArray::from(vec![Some("b1"), None]));
+        let c: ArrayRef = Arc::new(StringArray::from(vec![None, Some("c2")]));
+        let d: ArrayRef = Arc::new(StringArray::from(vec![Some("d1"), Some("d2")]));
+        let segment_schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Utf8, true),
+            Field::new("b", DataType::Utf8, true),
+            Field::new("c", DataType::Utf8, true),
+            Field::new("d", DataType::Utf8, true),
+        ]));
+        writer.write_batch(2, &[a.clone(), b.clone(), c.clone(), d.clone()])?;
+        writer.finish_current_buf()?;
+
+        // extra columns are ignored and the remaining ones are reordered by name
+        let expected_schema = Arc::new(Schema::new(vec![
+            Field::new("d", DataType::Utf8, true),
+            Field::new("a", DataType::Utf8, true),
+            Field::new("c", DataType::Utf8, true),
+        ]));
+        let mut reader = IpcCompressionReader::new(Cursor::new(buf.clone()));
+        let (num_rows, arrays) = reader
+            .read_batch_projected(&segment_schema, &expected_schema)?
+            .unwrap();
+        assert_eq!(num_rows, 2);
+        assert_eq!(arrays, &[d, a, c]);
+        assert!(reader
+            .read_batch_projected(&segment_schema, &expected_schema)?
+            .is_none());
+
+        // missing columns are reported
+        let missing_schema = Arc::new(Schema::new(vec![Field::new("e", DataType::Utf8, true)]));
+        let mut reader = IpcCompressionReader::new(Cursor::new(buf.clone()));
+        assert!(reader
+            .read_batch_projected(&segment_schema, &missing_schema)
+            .is_err());
+
+        // same-named columns with a different data type are reported
+        let mismatched_schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Utf8, true),
+            Field::new("b", DataType::Binary, true),
+        ]));
+        let mut reader = IpcCompressionReader::new(Cursor::new(buf));
+        let err = reader
+            .read_batch_projected(&segment_schema, &mismatched_schema)
+            .err()
+            .unwrap()
+            .to_string();
+        assert!(err.contains("mismatched data type of field in segment schema: b"));
+        Ok(())
+    }
 }
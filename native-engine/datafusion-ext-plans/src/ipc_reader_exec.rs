@@ -26,15 +26,16 @@ use arrow::{
 };
 use async_trait::async_trait;
 use blaze_jni_bridge::{
-    is_task_running, jni_call, jni_call_static, jni_get_byte_array_region, jni_get_direct_buffer,
-    jni_get_string, jni_new_direct_byte_buffer, jni_new_global_ref, jni_new_string,
+    conf, conf::BooleanConf, is_task_running, jni_call, jni_call_static, jni_get_byte_array_region,
+    jni_get_direct_buffer, jni_get_object_class, jni_get_string, jni_new_direct_byte_buffer,
+    jni_new_global_ref, jni_new_string,
 };
 use datafusion::{
     error::{DataFusionError, Result},
     execution::context::TaskContext,
     physical_expr::EquivalenceProperties,
     physical_plan::{
-        metrics::{ExecutionPlanMetricsSet, MetricsSet},
+        metrics::{BaselineMetrics, Count, ExecutionPlanMetricsSet, Gauge, MetricsSet},
         DisplayAs, DisplayFormatType, ExecutionMode, ExecutionPlan,
         Partitioning::UnknownPartitioning,
         PlanProperties, SendableRecordBatchStream, Statistics,
@@ -181,77 +182,35 @@ fn read_ipc_into_channel(
         let _timer = elapsed_compute.timer();
         log::info!("start ipc reading");
 
-        let size_counter = exec_ctx.register_counter_metric("size");
-        let batch_size = batch_size();
-        let output_batch_mem_size = suggested_output_batch_mem_size();
-        let mut staging_cols: Vec<Vec<ArrayRef>> = vec![];
-        let mut staging_num_rows = 0;
-        let mut staging_mem_size = 0;
-
-        while is_task_running() {
-            // get next block
-            let blocks = blocks.clone();
-            if !jni_call!(ScalaIterator(blocks.as_obj()).hasNext() -> bool)? {
-                break;
-            }
-            let next_block = jni_new_global_ref!(
-                jni_call!(ScalaIterator(blocks.as_obj()).next() -> JObject)?.as_obj()
-            )?;
-
-            // get ipc reader
-            let mut reader = match next_block {
-                b if jni_call!(BlazeBlockObject(b.as_obj()).hasFileSegment() -> bool)? => {
-                    get_file_reader(b.as_obj())?
-                }
-                b if jni_call!(BlazeBlockObject(b.as_obj()).hasByteBuffer() -> bool)? => {
-                    get_byte_buffer_reader(b.as_obj())?
-                }
-                b => get_channel_reader(b.as_obj())?,
-            };
-
-            while let Some((num_rows, cols)) = reader.read_batch(&exec_ctx.output_schema())? {
-                let mut cols_mem_size = 0;
-                staging_cols.resize_with(cols.len(), || vec![]);
-                for (col_idx, col) in cols.into_iter().enumerate() {
-                    cols_mem_size += col.get_array_mem_size();
-                    staging_cols[col_idx].push(col);
-                }
-                staging_num_rows += num_rows;
-                staging_mem_size += cols_mem_size;
-
-                if staging_num_rows >= batch_size || staging_mem_size >= output_batch_mem_size {
-                    let coalesced_cols = std::mem::take(&mut staging_cols)
-                        .into_iter()
-                        .map(|cols| coalesce_arrays_unchecked(cols[0].data_type(), &cols))
-                        .collect::<Vec<_>>();
-                    let batch = RecordBatch::try_new_with_options(
-                        exec_ctx.output_schema(),
-                        coalesced_cols,
-                        &RecordBatchOptions::new().with_row_count(Some(staging_num_rows)),
-                    )?;
-                    staging_num_rows = 0;
-                    staging_mem_size = 0;
-                    size_counter.add(batch.get_array_mem_size());
-                    exec_ctx.baseline_metrics().record_output(batch.num_rows());
+        let corrupted_blocks_counter = exec_ctx.register_counter_metric("corrupted_blocks");
+        let ignore_corrupted_blocks = conf::IGNORE_CORRUPTED_SHUFFLE_BLOCKS.value()?;
+        let mut staging = IpcBatchStaging::new(
+            exec_ctx.output_schema(),
+            batch_size(),
+            suggested_output_batch_mem_size(),
+            exec_ctx.baseline_metrics().clone(),
+            exec_ctx.register_counter_metric("size"),
+            exec_ctx.register_counter_metric("output_batches"),
+            exec_ctx.register_gauge_metric("max_batch_rows"),
+        );
+
+        read_ipc_blocks(
+            std::iter::from_fn(|| next_block(&blocks).transpose()),
+            &exec_ctx.output_schema(),
+            ignore_corrupted_blocks,
+            &corrupted_blocks_counter,
+            None,
+            |num_rows, cols| {
+                if let Some(batch) = staging.push(num_rows, cols)? {
                     if !elapsed_compute.exclude_timer(|| tx.send(batch)).is_ok() {
-                        break;
+                        return Ok(false);
                     }
                 }
-            }
-        }
+                Ok(true)
+            },
+        )?;
 
-        if staging_num_rows > 0 {
-            let coalesced_cols = staging_cols
-                .into_iter()
-                .map(|cols| coalesce_arrays_unchecked(cols[0].data_type(), &cols))
-                .collect::<Vec<_>>();
-            let batch = RecordBatch::try_new_with_options(
-                exec_ctx.output_schema(),
-                coalesced_cols,
-                &RecordBatchOptions::new().with_row_count(Some(staging_num_rows)),
-            )?;
-            size_counter.add(batch.get_array_mem_size());
-            exec_ctx.baseline_metrics().record_output(batch.num_rows());
+        if let Some(batch) = staging.flush()? {
             let _ = elapsed_compute.exclude_timer(|| tx.send(batch));
         }
         Ok::<_, DataFusionError>(())
@@ -259,6 +218,240 @@ fn read_ipc_into_channel(
     (rx, handle)
 }
 
+/// stages decoded columns and coalesces them into output batches once
+/// `batch_size` rows or `batch_mem_size` bytes are staged.
+///
+/// output_batches is counted next to output_rows, so the average batch size is
+/// output_rows / output_batches.
+struct IpcBatchStaging {
+    schema: SchemaRef,
+    batch_size: usize,
+    batch_mem_size: usize,
+    cols: Vec<Vec<ArrayRef>>,
+    num_rows: usize,
+    mem_size: usize,
+    baseline_metrics: BaselineMetrics,
+    size_counter: Count,
+    output_batches_counter: Count,
+    max_batch_rows_gauge: Gauge,
+}
+
+impl IpcBatchStaging {
+    fn new(
+        schema: SchemaRef,
+        batch_size: usize,
+        batch_mem_size: usize,
+        baseline_metrics: BaselineMetrics,
+        size_counter: Count,
+        output_batches_counter: Count,
+        max_batch_rows_gauge: Gauge,
+    ) -> Self {
+        Self {
+            schema,
+            batch_size,
+            batch_mem_size,
+            cols: vec![],
+            num_rows: 0,
+            mem_size: 0,
+            baseline_metrics,
+            size_counter,
+            output_batches_counter,
+            max_batch_rows_gauge,
+        }
+    }
+
+    /// stages a decoded batch, returns a coalesced batch if the staging is full
+    fn push(&mut self, num_rows: usize, cols: Vec<ArrayRef>) -> Result<Option<RecordBatch>> {
+        self.cols.resize_with(cols.len(), || vec![]);
+        for (col_idx, col) in cols.into_iter().enumerate() {
+            self.mem_size += col.get_array_mem_size();
+            self.cols[col_idx].push(col);
+        }
+        self.num_rows += num_rows;
+
+        if self.num_rows >= self.batch_size || self.mem_size >= self.batch_mem_size {
+            return self.flush();
+        }
+        Ok(None)
+    }
+
+    /// coalesces all staged columns into a batch, returns None if nothing
+    /// staged
+    fn flush(&mut self) -> Result<Option<RecordBatch>> {
+        if self.num_rows == 0 {
+            return Ok(None);
+        }
+        let coalesced_cols = std::mem::take(&mut self.cols)
+            .into_iter()
+            .map(|cols| coalesce_arrays_unchecked(cols[0].data_type(), &cols))
+            .collect::<Vec<_>>();
+        let batch = RecordBatch::try_new_with_options(
+            self.schema.clone(),
+            coalesced_cols,
+            &RecordBatchOptions::new().with_row_count(Some(self.num_rows)),
+        )?;
+        self.num_rows = 0;
+        self.mem_size = 0;
+        self.size_counter.add(batch.get_array_mem_size());
+        self.output_batches_counter.add(1);
+        self.max_batch_rows_gauge.set_max(batch.num_rows());
+        self.baseline_metrics.record_output(batch.num_rows());
+        Ok(Some(batch))
+    }
+}
+
+/// takes the next block from the jvm blocks iterator, returns None if the
+/// blocks are exhausted or the task is no longer running
+fn next_block(blocks: &GlobalRef) -> Result<Option<GlobalRef>> {
+    if !is_task_running() || !jni_call!(ScalaIterator(blocks.as_obj()).hasNext() -> bool)? {
+        return Ok(None);
+    }
+    let next_block = jni_call!(ScalaIterator(blocks.as_obj()).next() -> JObject)?;
+    Ok(Some(jni_new_global_ref!(next_block.as_obj())?))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum IpcBlockKind {
+    FileSegment,
+    ByteBuffer,
+    Channel,
+}
+
+/// diagnostic of a block, recording which reader was chosen for which kind of
+/// jvm block object
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct IpcBlockDiagnostic {
+    kind: IpcBlockKind,
+    class_name: String,
+}
+
+/// a shuffle block to be read, implemented by the jvm block objects
+trait IpcBlock {
+    fn kind(&self) -> Result<IpcBlockKind>;
+    fn class_name(&self) -> Result<String>;
+    fn open(&self, kind: IpcBlockKind) -> Result<IpcCompressionReader<Box<dyn Read + Send>>>;
+}
+
+impl IpcBlock for GlobalRef {
+    fn kind(&self) -> Result<IpcBlockKind> {
+        if jni_call!(BlazeBlockObject(self.as_obj()).hasFileSegment() -> bool)? {
+            return Ok(IpcBlockKind::FileSegment);
+        }
+        if jni_call!(BlazeBlockObject(self.as_obj()).hasByteBuffer() -> bool)? {
+            return Ok(IpcBlockKind::ByteBuffer);
+        }
+        Ok(IpcBlockKind::Channel)
+    }
+
+    fn class_name(&self) -> Result<String> {
+        let block_class = jni_get_object_class!(self.as_obj())?;
+        let block_class_name = jni_call!(Class(block_class.as_obj()).getName() -> JObject)?;
+        jni_get_string!(block_class_name.as_obj().into())
+    }
+
+    fn open(&self, kind: IpcBlockKind) -> Result<IpcCompressionReader<Box<dyn Read + Send>>> {
+        match kind {
+            IpcBlockKind::FileSegment => get_file_reader(self.as_obj()),
+            IpcBlockKind::ByteBuffer => get_byte_buffer_reader(self.as_obj()),
+            IpcBlockKind::Channel => get_channel_reader(self.as_obj()),
+        }
+    }
+}
+
+/// reads all blocks and passes the decoded batches to `on_batch`, until the
+/// blocks are exhausted or `on_batch` returns false. the reader kind and class
+/// of each block are logged at debug level, and also collected into
+/// `diagnostics` if provided.
+fn read_ipc_blocks<B: IpcBlock>(
+    blocks: impl Iterator<Item = Result<B>>,
+    schema: &SchemaRef,
+    ignore_corrupted_blocks: bool,
+    corrupted_blocks_counter: &Count,
+    mut diagnostics: Option<&mut Vec<IpcBlockDiagnostic>>,
+    mut on_batch: impl FnMut(usize, Vec<ArrayRef>) -> Result<bool>,
+) -> Result<()> {
+    for block in blocks {
+        let block = block?;
+        let kind = block.kind()?;
+
+        if diagnostics.is_some() || log::log_enabled!(log::Level::Debug) {
+            // class name is only for diagnostics, failing to get it is not fatal
+            let class_name = block
+                .class_name()
+                .unwrap_or_else(|err| format!("<unknown: {err}>"));
+            log::debug!("reading ipc block from {kind:?}, block class: {class_name}");
+            if let Some(diagnostics) = diagnostics.as_deref_mut() {
+                diagnostics.push(IpcBlockDiagnostic { kind, class_name });
+            }
+        }
+
+        let mut reader = match block.open(kind) {
+            Ok(reader) => reader,
+            Err(err) if ignore_corrupted_blocks && is_corrupted_block_error(&err) => {
+                log::warn!("skipping corrupted ipc block from {kind:?}: {err}");
+                corrupted_blocks_counter.add(1);
+                continue;
+            }
+            Err(err) => return Err(err),
+        };
+        let channel_open = read_ipc_block(
+            &mut reader,
+            schema,
+            ignore_corrupted_blocks,
+            corrupted_blocks_counter,
+            &mut on_batch,
+        )?;
+        if !channel_open {
+            // output channel is closed, no need to read further blocks
+            break;
+        }
+    }
+    Ok(())
+}
+
+/// reads all batches of an ipc block and passes them to `on_batch`, until the
+/// block is exhausted or `on_batch` returns false. if `ignore_corrupted_blocks`
+/// is set, a decoding error skips the rest of the block instead of failing.
+/// returns false if reading was stopped by `on_batch`.
+fn read_ipc_block<R: Read + 'static>(
+    reader: &mut IpcCompressionReader<R>,
+    schema: &SchemaRef,
+    ignore_corrupted_blocks: bool,
+    corrupted_blocks_counter: &Count,
+    mut on_batch: impl FnMut(usize, Vec<ArrayRef>) -> Result<bool>,
+) -> Result<bool> {
+    loop {
+        let (num_rows, cols) = match reader.read_batch(schema) {
+            Ok(Some(batch)) => batch,
+            Ok(None) => return Ok(true),
+            Err(err) if ignore_corrupted_blocks && is_corrupted_block_error(&err) => {
+                log::warn!("skipping the rest of corrupted ipc block: {err}");
+                corrupted_blocks_counter.add(1);
+                return Ok(true);
+            }
+            Err(err) => return Err(err),
+        };
+        if !on_batch(num_rows, cols)? {
+            return Ok(false);
+        }
+    }
+}
+
+/// returns whether an error is caused by corrupted block data. errors raised
+/// from the jvm side (like FetchFailedException) are wrapped in
+/// `DataFusionError::External` and are never treated as corruption, so that
+/// spark can still retry the failed fetch.
+fn is_corrupted_block_error(err: &DataFusionError) -> bool {
+    match err {
+        DataFusionError::IoError(io_err) => !io_err
+            .get_ref()
+            .is_some_and(|inner| inner.is::<DataFusionError>()),
+        DataFusionError::ArrowError(..) | DataFusionError::Execution(_) => true,
+        DataFusionError::Context(_, inner) => is_corrupted_block_error(inner),
+        _ => false,
+    }
+}
+
 fn get_channel_reader(block: JObject) -> Result<IpcCompressionReader<Box<dyn Read + Send>>> {
     let channel_reader = ReadableByteChannelReader::try_new(block)?;
 
@@ -272,14 +465,37 @@ fn get_file_reader(block: JObject) -> Result<IpcCompressionReader<Box<dyn Read +
     let path = jni_get_string!(path.as_obj().into())?;
     let offset = jni_call!(BlazeBlockObject(block).getFileOffset() -> i64)?;
     let length = jni_call!(BlazeBlockObject(block).getFileLength() -> i64)?;
-    let mut file = File::open(&path)?;
-    file.seek(SeekFrom::Start(offset as u64))?;
+    let segment = open_file_segment(&path, offset, length)?;
 
     Ok(IpcCompressionReader::new(Box::new(
-        BufReader::with_capacity(65536, file.take(length as u64)),
+        BufReader::with_capacity(65536, segment),
     )))
 }
 
+/// open a segment of the file starting from offset, a negative length means
+/// the segment spans to the end of file
+fn open_file_segment(path: &str, offset: i64, length: i64) -> Result<Box<dyn Read + Send>> {
+    let mut file = File::open(path)?;
+    let file_len = file.metadata()?.len();
+    let segment_end = if length >= 0 {
+        offset.checked_add(length)
+    } else {
+        Some(offset)
+    };
+    if offset < 0 || segment_end.map_or(true, |end| end as u64 > file_len) {
+        return df_execution_err!(
+            "file segment out of range: path={path}, offset={offset}, length={length}, \
+             file_len={file_len}"
+        );
+    }
+    file.seek(SeekFrom::Start(offset as u64))?;
+
+    if length < 0 {
+        return Ok(Box::new(file));
+    }
+    Ok(Box::new(file.take(length as u64)))
+}
+
 fn get_byte_buffer_reader(block: JObject) -> Result<IpcCompressionReader<Box<dyn Read + Send>>> {
     let byte_buffer = jni_call!(BlazeBlockObject(block).getByteBuffer() -> JObject)?;
     if jni_call!(JavaBuffer(byte_buffer.as_obj()).isDirect() -> bool)? {
@@ -446,3 +662,298 @@ impl Drop for HeapByteBufferReader {
         let _ = self.block;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{error::Error, io::Write, sync::Arc};
+
+    use arrow::{
+        array::Int32Array,
+        datatypes::{DataType, Field, Schema},
+    };
+
+    use super::*;
+    use crate::common::ipc_compression::IpcCompressionWriter;
+
+    #[test]
+    fn test_ipc_batch_staging() -> Result<(), Box<dyn Error>> {
+        let schema = Arc::new(Schema::new(vec![Field::new("", DataType::Int32, true)]));
+        let baseline_metrics = BaselineMetrics::new(&ExecutionPlanMetricsSet::new(), 0);
+        let output_batches = Count::new();
+        let max_batch_rows = Gauge::new();
+        let mut staging = IpcBatchStaging::new(
+            schema,
+            8,
+            usize::MAX,
+            baseline_metrics.clone(),
+            Count::new(),
+            output_batches.clone(),
+            max_batch_rows.clone(),
+        );
+
+        let mut output_batch_rows = vec![];
+        for num_rows in [3, 5, 2, 7, 4] {
+            let array: ArrayRef = Arc::new(Int32Array::from(vec![1; num_rows]));
+            if let Some(batch) = staging.push(num_rows, vec![array])? {
+                output_batch_rows.push(batch.num_rows());
+            }
+        }
+        if let Some(batch) = staging.flush()? {
+            output_batch_rows.push(batch.num_rows());
+        }
+        assert!(staging.flush()?.is_none());
+
+        assert_eq!(output_batch_rows, vec![8, 9, 4]);
+        assert_eq!(output_batches.value(), 3);
+        assert_eq!(baseline_metrics.output_rows().value(), 21);
+        assert_eq!(max_batch_rows.value(), 9);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_ipc_block_ignore_corrupted() -> Result<(), Box<dyn Error>> {
+        let schema = Arc::new(Schema::new(vec![Field::new("", DataType::Int32, true)]));
+        let mut blocks = vec![];
+        for i in 0..3 {
+            let mut buf = vec![];
+            let mut writer = IpcCompressionWriter::new(&mut buf);
+            let array: ArrayRef = Arc::new(Int32Array::from(vec![Some(i), None, Some(i + 1)]));
+            writer.write_batch(3, &[array])?;
+            writer.finish_current_buf()?;
+            drop(writer);
+            blocks.push(buf);
+        }
+
+        // corrupt the frame header of the second block
+        blocks[1][4] ^= 0xff;
+
+        let read_blocks = |ignore_corrupted_blocks: bool, counter: &Count| -> Result<Vec<i32>> {
+            let mut values = vec![];
+            for block in &blocks {
+                let mut reader = IpcCompressionReader::new(Cursor::new(block.clone()));
+                read_ipc_block(
+                    &mut reader,
+                    &schema,
+                    ignore_corrupted_blocks,
+                    counter,
+                    |num_rows, cols| {
+                        assert_eq!(num_rows, 3);
+                        let array = cols[0].as_any().downcast_ref::<Int32Array>().unwrap();
+                        values.extend(array.iter().flatten());
+                        Ok(true)
+                    },
+                )?;
+            }
+            Ok(values)
+        };
+
+        let corrupted_blocks = Count::new();
+        assert_eq!(read_blocks(true, &corrupted_blocks)?, vec![0, 1, 2, 3]);
+        assert_eq!(corrupted_blocks.value(), 1);
+
+        let corrupted_blocks = Count::new();
+        assert!(read_blocks(false, &corrupted_blocks).is_err());
+        assert_eq!(corrupted_blocks.value(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_ipc_block_jvm_error_not_ignored() -> Result<(), Box<dyn Error>> {
+        struct JvmErrorReader;
+        impl Read for JvmErrorReader {
+            fn read(&mut self, _buf: &mut [u8]) -> std::io::Result<usize> {
+                Err(std::io::Error::other(DataFusionError::External(
+                    "Java exception thrown: FetchFailedException".into(),
+                )))
+            }
+        }
+
+        let schema = Arc::new(Schema::new(vec![Field::new("", DataType::Int32, true)]));
+        let corrupted_blocks = Count::new();
+        let mut reader = IpcCompressionReader::new(JvmErrorReader);
+        let result = read_ipc_block(&mut reader, &schema, true, &corrupted_blocks, |_, _| {
+            Ok(true)
+        });
+        assert!(result.is_err());
+        assert_eq!(corrupted_blocks.value(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_ipc_blocks_diagnostics() -> Result<(), Box<dyn Error>> {
+        struct TestBlock {
+            kind: IpcBlockKind,
+            class_name: Option<&'static str>,
+            data: Vec<u8>,
+        }
+        impl IpcBlock for TestBlock {
+            fn kind(&self) -> Result<IpcBlockKind> {
+                Ok(self.kind)
+            }
+
+            fn class_name(&self) -> Result<String> {
+                match self.class_name {
+                    Some(class_name) => Ok(class_name.to_string()),
+                    None => df_execution_err!("class not found"),
+                }
+            }
+
+            fn open(
+                &self,
+                kind: IpcBlockKind,
+            ) -> Result<IpcCompressionReader<Box<dyn Read + Send>>> {
+                assert_eq!(kind, self.kind);
+                if self.data.is_empty() {
+                    return df_execution_err!("cannot open block");
+                }
+                Ok(IpcCompressionReader::new(Box::new(Cursor::new(
+                    self.data.clone(),
+                ))))
+            }
+        }
+
+        let schema = Arc::new(Schema::new(vec![Field::new("", DataType::Int32, true)]));
+        let block_data = |i: i32| -> Result<Vec<u8>> {
+            let mut buf = vec![];
+            let mut writer = IpcCompressionWriter::new(&mut buf);
+            let array: ArrayRef = Arc::new(Int32Array::from(vec![i]));
+            writer.write_batch(1, &[array])?;
+            writer.finish_current_buf()?;
+            drop(writer);
+            Ok(buf)
+        };
+        let blocks = vec![
+            TestBlock {
+                kind: IpcBlockKind::FileSegment,
+                class_name: Some("FileSegmentBlock"),
+                data: block_data(0)?,
+            },
+            TestBlock {
+                kind: IpcBlockKind::Channel,
+                class_name: Some("ChannelBlock"),
+                data: vec![], // fails to open, skipped
+            },
+            TestBlock {
+                kind: IpcBlockKind::ByteBuffer,
+                class_name: None,
+                data: block_data(1)?,
+            },
+            TestBlock {
+                kind: IpcBlockKind::Channel,
+                class_name: Some("ChannelBlock"),
+                data: block_data(2)?,
+            },
+        ];
+
+        let mut values = vec![];
+        let mut diagnostics = vec![];
+        let corrupted_blocks = Count::new();
+        read_ipc_blocks(
+            blocks.into_iter().map(Ok),
+            &schema,
+            true,
+            &corrupted_blocks,
+            Some(&mut diagnostics),
+            |_, cols| {
+                let array = cols[0].as_any().downcast_ref::<Int32Array>().unwrap();
+                values.extend(array.iter().flatten());
+                Ok(true)
+            },
+        )?;
+
+        assert_eq!(values, vec![0, 1, 2]);
+        assert_eq!(corrupted_blocks.value(), 1);
+        assert_eq!(
+            diagnostics
+                .iter()
+                .map(|diagnostic| diagnostic.kind)
+                .collect::<Vec<_>>(),
+            vec![
+                IpcBlockKind::FileSegment,
+                IpcBlockKind::Channel,
+                IpcBlockKind::ByteBuffer,
+                IpcBlockKind::Channel,
+            ]
+        );
+        assert_eq!(diagnostics[0].class_name, "FileSegmentBlock");
+        assert_eq!(diagnostics[1].class_name, "ChannelBlock");
+        assert!(diagnostics[2].class_name.starts_with("<unknown"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_ipc_block_stopped() -> Result<(), Box<dyn Error>> {
+        let schema = Arc::new(Schema::new(vec![Field::new("", DataType::Int32, true)]));
+        let mut buf = vec![];
+        let mut writer = IpcCompressionWriter::new(&mut buf);
+        for i in 0..3 {
+            let array: ArrayRef = Arc::new(Int32Array::from(vec![i]));
+            writer.write_batch(1, &[array])?;
+            writer.finish_current_buf()?;
+        }
+        drop(writer);
+
+        let read_block = |max_batches: usize| -> Result<(bool, usize)> {
+            let mut reader = IpcCompressionReader::new(Cursor::new(buf.clone()));
+            let mut num_batches = 0;
+            let completed = read_ipc_block(&mut reader, &schema, false, &Count::new(), |_, _| {
+                num_batches += 1;
+                Ok(num_batches < max_batches)
+            })?;
+            Ok((completed, num_batches))
+        };
+        assert_eq!(read_block(usize::MAX)?, (true, 3));
+        assert_eq!(read_block(2)?, (false, 2));
+        Ok(())
+    }
+
+    #[test]
+    fn test_open_file_segment_to_eof() -> Result<(), Box<dyn Error>> {
+        let schema = Arc::new(Schema::new(vec![Field::new("", DataType::Int32, true)]));
+        let mut file = tempfile::NamedTempFile::new()?;
+        file.write_all(b"garbage before segment")?;
+        let offset = file.as_file_mut().stream_position()?;
+
+        let mut writer = IpcCompressionWriter::new(file.as_file_mut());
+        for i in 0..3 {
+            let array: ArrayRef = Arc::new(Int32Array::from(vec![Some(i), None, Some(i + 1)]));
+            writer.write_batch(3, &[array])?;
+            writer.finish_current_buf()?;
+        }
+        drop(writer);
+
+        let path = file.path().to_str().unwrap();
+        let segment = open_file_segment(path, offset as i64, -1)?;
+        let mut reader = IpcCompressionReader::new(segment);
+        let mut num_batches = 0;
+        while let Some((num_rows, cols)) = reader.read_batch(&schema)? {
+            let expected: ArrayRef = Arc::new(Int32Array::from(vec![
+                Some(num_batches),
+                None,
+                Some(num_batches + 1),
+            ]));
+            assert_eq!(num_rows, 3);
+            assert_eq!(&cols[0], &expected);
+            num_batches += 1;
+        }
+        assert_eq!(num_batches, 3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_open_file_segment_past_eof() -> Result<(), Box<dyn Error>> {
+        let mut file = tempfile::NamedTempFile::new()?;
+        file.write_all(&[0u8; 100])?;
+        let path = file.path().to_str().unwrap();
+
+        assert!(open_file_segment(path, 50, 50).is_ok());
+        let err = open_file_segment(path, 50, 51).err().unwrap().to_string();
+        assert!(err.contains(&format!("path={path}")));
+        assert!(err.contains("offset=50"));
+        assert!(err.contains("length=51"));
+        assert!(err.contains("file_len=100"));
+        assert!(open_file_segment(path, 101, -1).is_err());
+        assert!(open_file_segment(path, 50, i64::MAX).is_err());
+        Ok(())
+    }
+}
@@ -60,7 +60,10 @@ case class NativeShuffleExchangeExec(
           "disk_spill_iotime",
           "sort_time",
           "output_io_time",
-          "shuffle_read_total_time"))
+          "shuffle_read_total_time",
+          "shuffle_read_output_batches",
+          "shuffle_read_corrupted_blocks",
+          "shuffle_read_max_batch_rows"))
         .toSeq: _*)).toMap
 
   lazy val readMetrics: Map[String, SQLMetric] =
@@ -37,6 +37,10 @@ public enum BlazeConf {
     /// ignore corrupted input files
     IGNORE_CORRUPTED_FILES("spark.files.ignoreCorruptFiles", false),
 
+    /// skip the rest of a shuffle block when it fails to decode instead of failing the task.
+    /// rows decoded from the block before the failure are kept, so this may lose data.
+    IGNORE_CORRUPTED_SHUFFLE_BLOCKS("spark.blaze.ignoreCorruptedShuffleBlocks", false),
+
     /// enable partial aggregate skipping (see https://github.com/blaze-init/blaze/issues/327)
     PARTIAL_AGG_SKIPPING_ENABLE("spark.blaze.partialAggSkipping.enable", true),
 
@@ -111,7 +111,11 @@ object NativeHelper extends Logging {
       "disk_spill_iotime" -> nanoTimingMetric("Native.disk_spill_iotime"),
       "sort_time" -> nanoTimingMetric("Native.sort_time"),
       "output_io_time" -> nanoTimingMetric("Native.output_io_time"),
-      "shuffle_read_total_time" -> nanoTimingMetric("Native.shuffle_read_total_time"))
+      "shuffle_read_total_time" -> nanoTimingMetric("Native.shuffle_read_total_time"),
+      "shuffle_read_output_batches" -> metric("Native.shuffle_read_output_batches"),
+      "shuffle_read_corrupted_blocks" -> metric("Native.shuffle_read_corrupted_blocks"),
+      // per-task values are shown as (min, med, max) across tasks
+      "shuffle_read_max_batch_rows" -> sizeMetric("Native.shuffle_read_max_batch_rows"))
 
     if (BlazeConf.INPUT_BATCH_STATISTICS_ENABLE.booleanConf()) {
       metrics ++= TreeMap(
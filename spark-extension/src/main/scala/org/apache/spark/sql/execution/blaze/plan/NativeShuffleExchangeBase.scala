@@ -106,6 +106,9 @@ abstract class NativeShuffleExchangeBase(
           new SQLShuffleReadMetricsReporter(shuffleReadMetrics, metrics).incRecordsRead(v)
           TaskContext.get.taskMetrics().mergeShuffleReadMetrics()
         case ("elapsed_compute", v) => metrics("shuffle_read_total_time") += v
+        case ("output_batches", v) => metrics("shuffle_read_output_batches") += v
+        case ("max_batch_rows", v) => metrics("shuffle_read_max_batch_rows") += v
+        case ("corrupted_blocks", v) => metrics("shuffle_read_corrupted_blocks") += v
         case _ =>
       }))
 